@@ -13,7 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use angle::Rad;
+use std::num;
+
+use angle::{Rad, rad};
 use array::Array;
 use matrix::Matrix;
 use matrix::{Mat2, ToMat2};
@@ -35,6 +37,11 @@ pub trait Rotation
 +   ApproxEq<S>
 {
     fn identity() -> Self;
+
+    /// Create a rotation that rotates the unit vector `a` onto the unit
+    /// vector `b`, taking the shortest path between them.
+    fn between_vectors(a: &V, b: &V) -> Self;
+
     fn rotate_vec(&self, vec: &V) -> V;
 
     #[inline]
@@ -82,7 +89,25 @@ pub trait Rotation3
 +   ToMat3<S>
 +   ToBasis3<S>
 +   ToQuat<S>
-{}
+{
+    /// Spherically interpolate between this rotation and `other`, returning
+    /// the unit quaternion lying `amount` of the way between them along the
+    /// shortest great-circle arc.
+    fn slerp(&self, other: &Self, amount: S) -> Quat<S>;
+
+    /// Normalized linearly interpolate between this rotation and `other`.
+    /// Cheaper than `slerp`, but does not produce a constant angular
+    /// velocity as `amount` varies.
+    fn nlerp(&self, other: &Self, amount: S) -> Quat<S>;
+
+    /// Decompose this rotation into the `(x, y, z)` euler angles that
+    /// `from_euler` would have built it from.
+    fn to_euler(&self) -> (Rad<S>, Rad<S>, Rad<S>);
+
+    /// Decompose this rotation into a unit axis and the angle rotated
+    /// around it.
+    fn to_axis_angle(&self) -> (Vec3<S>, Rad<S>);
+}
 
 
 /// A two-dimensional rotation matrix.
@@ -118,7 +143,12 @@ impl<S: Float> ToMat2<S> for Basis2<S> {
 impl<S: Float> Rotation<S, [S, ..2], Vec2<S>, Point2<S>> for Basis2<S> {
     #[inline]
     fn identity() -> Basis2<S> { Basis2{ mat: Mat2::identity() } }
-    
+
+    #[inline]
+    fn between_vectors(a: &Vec2<S>, b: &Vec2<S>) -> Basis2<S> {
+        Basis2 { mat: Mat2::from_angle(rad((a.x * b.y - a.y * b.x).atan2(&a.dot(b)))) }
+    }
+
     #[inline]
     fn rotate_vec(&self, vec: &Vec2<S>) -> Vec2<S> { self.mat.mul_v(vec) }
 
@@ -128,15 +158,15 @@ impl<S: Float> Rotation<S, [S, ..2], Vec2<S>, Point2<S>> for Basis2<S> {
     #[inline]
     fn concat_self(&mut self, other: &Basis2<S>) { self.mat.mul_self_m(&other.mat); }
 
-    // TODO: we know the matrix is orthogonal, so this could be re-written
-    // to be faster
+    // the matrix is guaranteed to be orthogonal, so its inverse is simply
+    // its transpose
     #[inline]
-    fn invert(&self) -> Basis2<S> { Basis2 { mat: self.mat.invert().unwrap() } }
+    fn invert(&self) -> Basis2<S> { Basis2 { mat: self.mat.transpose() } }
 
-    // TODO: we know the matrix is orthogonal, so this could be re-written
-    // to be faster
+    // the matrix is guaranteed to be orthogonal, so its inverse is simply
+    // its transpose
     #[inline]
-    fn invert_self(&mut self) { self.mat.invert_self(); }
+    fn invert_self(&mut self) { self.mat.transpose_self(); }
 }
 
 impl<S: Float> ApproxEq<S> for Basis2<S> {
@@ -233,7 +263,12 @@ impl<S: Float> ToQuat<S> for Basis3<S> {
 impl<S: Float> Rotation<S, [S, ..3], Vec3<S>, Point3<S>> for Basis3<S> {
     #[inline]
     fn identity() -> Basis3<S> { Basis3{ mat: Mat3::identity() } }
-    
+
+    #[inline]
+    fn between_vectors(a: &Vec3<S>, b: &Vec3<S>) -> Basis3<S> {
+        Quat::between_vectors(a, b).to_rot3()
+    }
+
     #[inline]
     fn rotate_vec(&self, vec: &Vec3<S>) -> Vec3<S> { self.mat.mul_v(vec) }
 
@@ -243,15 +278,15 @@ impl<S: Float> Rotation<S, [S, ..3], Vec3<S>, Point3<S>> for Basis3<S> {
     #[inline]
     fn concat_self(&mut self, other: &Basis3<S>) { self.mat.mul_self_m(&other.mat); }
 
-    // TODO: we know the matrix is orthogonal, so this could be re-written
-    // to be faster
+    // the matrix is guaranteed to be orthogonal, so its inverse is simply
+    // its transpose
     #[inline]
-    fn invert(&self) -> Basis3<S> { Basis3 { mat: self.mat.invert().unwrap() } }
+    fn invert(&self) -> Basis3<S> { Basis3 { mat: self.mat.transpose() } }
 
-    // TODO: we know the matrix is orthogonal, so this could be re-written
-    // to be faster
+    // the matrix is guaranteed to be orthogonal, so its inverse is simply
+    // its transpose
     #[inline]
-    fn invert_self(&mut self) { self.mat.invert_self(); }
+    fn invert_self(&mut self) { self.mat.transpose_self(); }
 }
 
 impl<S: Float> ApproxEq<S> for Basis3<S> {
@@ -272,7 +307,45 @@ impl<S: Float> ApproxEq<S> for Basis3<S> {
     }
 }
 
-impl<S: Float> Rotation3<S> for Basis3<S> {}
+impl<S: Float> Rotation3<S> for Basis3<S> {
+    #[inline]
+    fn slerp(&self, other: &Basis3<S>, amount: S) -> Quat<S> {
+        self.to_quat().slerp(&other.to_quat(), amount)
+    }
+
+    #[inline]
+    fn nlerp(&self, other: &Basis3<S>, amount: S) -> Quat<S> {
+        self.to_quat().nlerp(&other.to_quat(), amount)
+    }
+
+    fn to_euler(&self) -> (Rad<S>, Rad<S>, Rad<S>) {
+        let m = &self.mat;
+
+        // clamp to guard against floating point drift pushing the `asin`
+        // argument outside [-1, 1] right at the gimbal-lock poles
+        let sy = (-m.x.z).max(-num::one::<S>()).min(num::one::<S>());
+        let y = sy.asin();
+
+        if sy.abs() > num::cast(0.9999) {
+            // gimbal lock: the x and z axes have become coincident, so only
+            // their sum (at y = -pi/2) or difference (at y = pi/2) is
+            // determined. Attribute the whole swing to `x` and zero out `z`.
+            let x = if sy > num::zero() {
+                m.y.x.atan2(&m.y.y)
+            } else {
+                (-m.y.x).atan2(&m.y.y)
+            };
+            (rad(x), rad(y), rad(num::zero()))
+        } else {
+            (rad(m.y.z.atan2(&m.z.z)), rad(y), rad(m.x.y.atan2(&m.x.x)))
+        }
+    }
+
+    #[inline]
+    fn to_axis_angle(&self) -> (Vec3<S>, Rad<S>) {
+        self.to_quat().to_axis_angle()
+    }
+}
 
 // Quaternion Rotation impls
 
@@ -286,10 +359,84 @@ impl<S: Float> ToQuat<S> for Quat<S> {
     fn to_quat(&self) -> Quat<S> { self.clone() }
 }
 
+impl<S: Float> Quat<S> {
+    /// Spherically linear interpolate between this quaternion and `other`.
+    ///
+    /// Both quaternions are assumed to be normalized. The shortest arc
+    /// between the two orientations is taken by negating `other` (and its
+    /// dot product with `self`) when they point into opposite hemispheres.
+    /// When the two quaternions are nearly coincident the formula below
+    /// would divide by a near-zero sine, so `nlerp` is used instead.
+    pub fn slerp(&self, other: &Quat<S>, amount: S) -> Quat<S> {
+        let mut dot = self.dot(other);
+        let mut other = other.clone();
+
+        if dot < num::zero() {
+            other = other.mul_s(-num::one::<S>());
+            dot = -dot;
+        }
+
+        if dot > num::cast(0.9995) {
+            self.nlerp(&other, amount)
+        } else {
+            let theta_0 = dot.acos();
+            let theta = theta_0 * amount;
+            let q_perp = other.sub_q(&self.mul_s(dot)).normalize();
+
+            self.mul_s(theta.cos()).add_q(&q_perp.mul_s(theta.sin()))
+        }
+    }
+
+    /// Normalized linear interpolation between this quaternion and `other`.
+    /// Cheaper than `slerp`, but does not move at a constant angular rate.
+    pub fn nlerp(&self, other: &Quat<S>, amount: S) -> Quat<S> {
+        self.add_q(&other.sub_q(self).mul_s(amount)).normalize()
+    }
+
+    /// Construct the rotation that carries the unit vector `a` onto the
+    /// unit vector `b`, taking the shortest path between them.
+    pub fn between_vectors(a: &Vec3<S>, b: &Vec3<S>) -> Quat<S> {
+        let c = a.dot(b);
+
+        if c < -num::one::<S>() + num::cast(1.0e-6) {
+            // `a` and `b` point in (near) opposite directions, so `a x b`
+            // is degenerate. Rotate by pi around any axis orthogonal to `a`.
+            let axis = if a.x.abs() < a.y.abs() {
+                Vec3::unit_x().cross(a)
+            } else {
+                Vec3::unit_y().cross(a)
+            }.normalize();
+
+            Quat::from_axis_angle(&axis, rad(Float::pi()))
+        } else {
+            let axis = a.cross(b);
+            Quat::from_sv(num::one::<S>() + c, axis).normalize()
+        }
+    }
+
+    /// Decompose this unit quaternion into a unit axis and the angle
+    /// rotated around it.
+    pub fn to_axis_angle(&self) -> (Vec3<S>, Rad<S>) {
+        let angle = self.s.acos() * num::cast(2);
+
+        let axis = if self.v.dot(&self.v) < num::cast(1.0e-12) {
+            // the angle is (close to) zero, so any axis will do
+            Vec3::unit_x()
+        } else {
+            self.v.normalize()
+        };
+
+        (axis, rad(angle))
+    }
+}
+
 impl<S: Float> Rotation<S, [S, ..3], Vec3<S>, Point3<S>> for Quat<S> {
     #[inline]
-    fn identity() -> Quat<S> { Quat::identity() }  
-    
+    fn identity() -> Quat<S> { Quat::identity() }
+
+    #[inline]
+    fn between_vectors(a: &Vec3<S>, b: &Vec3<S>) -> Quat<S> { Quat::between_vectors(a, b) }
+
     #[inline]
     fn rotate_vec(&self, vec: &Vec3<S>) -> Vec3<S> { self.mul_v(vec) }
 
@@ -306,4 +453,16 @@ impl<S: Float> Rotation<S, [S, ..3], Vec3<S>, Point3<S>> for Quat<S> {
     fn invert_self(&mut self) { *self = self.invert() }
 }
 
-impl<S: Float> Rotation3<S> for Quat<S> {}
+impl<S: Float> Rotation3<S> for Quat<S> {
+    #[inline]
+    fn slerp(&self, other: &Quat<S>, amount: S) -> Quat<S> { Quat::slerp(self, other, amount) }
+
+    #[inline]
+    fn nlerp(&self, other: &Quat<S>, amount: S) -> Quat<S> { Quat::nlerp(self, other, amount) }
+
+    #[inline]
+    fn to_euler(&self) -> (Rad<S>, Rad<S>, Rad<S>) { self.to_rot3().to_euler() }
+
+    #[inline]
+    fn to_axis_angle(&self) -> (Vec3<S>, Rad<S>) { Quat::to_axis_angle(self) }
+}
@@ -0,0 +1,117 @@
+// Copyright 2013 The CGMath Developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::num;
+
+use matrix::{Mat3, Mat4};
+use point::Point;
+use rotation::{Rotation, Rotation2, Rotation3};
+use vector::{Vector, Vec2, Vec3};
+
+/// A rigid-body transform: a uniform `scale`, followed by a `rot`ation,
+/// followed by a `disp`lacement.
+///
+/// Unlike `Basis2`/`Basis3`, which wrap a matrix to guarantee orthogonality,
+/// `Decomposed` is a plain aggregate of its parts (following the approach
+/// nalgebra takes with `Iso2`/`Iso3`), so any `Rotation` implementation can
+/// be plugged in as the `rot` backend. `R` will typically be `Basis2<S>`,
+/// `Basis3<S>`, or `Quat<S>`.
+#[deriving(Eq, Clone)]
+pub struct Decomposed<S, V, R> {
+    pub scale: S,
+    pub rot: R,
+    pub disp: V,
+}
+
+impl
+<
+    S: Primitive,
+    Slice,
+    V: Vector<S, Slice>,
+    P: Point<S, V, Slice>,
+    R: Rotation<S, Slice, V, P>
+>
+Decomposed<S, V, R>
+{
+    #[inline]
+    pub fn identity() -> Decomposed<S, V, R> {
+        Decomposed {
+            scale: num::one(),
+            rot: Rotation::identity(),
+            disp: Vector::zero(),
+        }
+    }
+
+    #[inline]
+    pub fn transform_vec(&self, vec: &V) -> V {
+        self.rot.rotate_vec(&vec.mul_s(self.scale))
+    }
+
+    #[inline]
+    pub fn transform_point(&self, point: &P) -> P {
+        Point::from_vec(&self.transform_vec(&point.to_vec())).add_v(&self.disp)
+    }
+
+    #[inline]
+    pub fn concat(&self, other: &Decomposed<S, V, R>) -> Decomposed<S, V, R> {
+        Decomposed {
+            scale: self.scale * other.scale,
+            rot: self.rot.concat(&other.rot),
+            disp: self.transform_vec(&other.disp).add_v(&self.disp),
+        }
+    }
+
+    #[inline]
+    pub fn concat_self(&mut self, other: &Decomposed<S, V, R>) {
+        *self = self.concat(other);
+    }
+
+    #[inline]
+    pub fn invert(&self) -> Decomposed<S, V, R> {
+        let scale = num::one::<S>() / self.scale;
+        let rot = self.rot.invert();
+        Decomposed {
+            scale: scale,
+            disp: rot.rotate_vec(&self.disp.mul_s(-scale)),
+            rot: rot,
+        }
+    }
+
+    #[inline]
+    pub fn invert_self(&mut self) {
+        *self = self.invert();
+    }
+}
+
+impl<S: Float, R: Rotation2<S>> Decomposed<S, Vec2<S>, R> {
+    /// Emit this 2D isometry as a homogeneous 3x3 matrix.
+    pub fn to_mat3(&self) -> Mat3<S> {
+        let m = self.rot.to_mat2();
+        Mat3::new(m.x.x * self.scale, m.x.y * self.scale, num::zero(),
+                  m.y.x * self.scale, m.y.y * self.scale, num::zero(),
+                  self.disp.x,        self.disp.y,        num::one())
+    }
+}
+
+impl<S: Float, R: Rotation3<S>> Decomposed<S, Vec3<S>, R> {
+    /// Emit this 3D isometry as a homogeneous 4x4 matrix.
+    pub fn to_mat4(&self) -> Mat4<S> {
+        let m = self.rot.to_mat3();
+        Mat4::new(m.x.x * self.scale, m.x.y * self.scale, m.x.z * self.scale, num::zero(),
+                  m.y.x * self.scale, m.y.y * self.scale, m.y.z * self.scale, num::zero(),
+                  m.z.x * self.scale, m.z.y * self.scale, m.z.z * self.scale, num::zero(),
+                  self.disp.x,        self.disp.y,        self.disp.z,        num::one())
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright 2013 The Lmath Developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::num;
+use std::cast;
+
+use color::{Channel, ToChannel, FloatChannel, ToFloatChannel};
+use color::{RGB, ToRGB, RGBA, ToRGBA};
+
+#[path = "../num_macros.rs"]
+mod num_macros;
+
+#[deriving(Clone, Eq)]
+pub struct HSL<T> { h: T, s: T, l: T }
+
+impl<T> HSL<T> {
+    pub fn new(h: T, s: T, l: T) -> HSL<T> {
+        HSL { h: h, s: s, l: l }
+    }
+}
+
+pub trait ToHSL {
+    pub fn to_hsl<U:Clone + FloatChannel>(&self) -> HSL<U>;
+}
+
+impl<T:Clone + ToFloatChannel> ToHSL for HSL<T> {
+    #[inline]
+    pub fn to_hsl<U:Clone + FloatChannel>(&self) -> HSL<U> {
+        HSL::new(FloatChannel::from((*self).h.clone()),
+                 FloatChannel::from((*self).s.clone()),
+                 FloatChannel::from((*self).l.clone()))
+    }
+}
+
+impl<T:Clone + Float + ToChannel> ToRGB for HSL<T> {
+    pub fn to_rgb<U:Clone + Channel>(&self) -> RGB<U> {
+        // Algorithm taken from the Wikipedia article on HSL and HSV:
+        // http://en.wikipedia.org/wiki/HSL_and_HSV#From_HSL
+
+        let chr = (one!(T) - (two!(T) * (*self).l - one!(T)).abs()) * (*self).s;
+        let h = (*self).h / num::cast(60);
+
+        // the 2nd largest component
+        let x = chr * (one!(T) - ((h % two!(T)) - one!(T)).abs());
+
+        let mut rgb = cond! (
+            (h < num::cast(1)) { RGB::new(chr.clone(), x, zero!(T)) }
+            (h < num::cast(2)) { RGB::new(x, chr.clone(), zero!(T)) }
+            (h < num::cast(3)) { RGB::new(zero!(T), chr.clone(), x) }
+            (h < num::cast(4)) { RGB::new(zero!(T), x, chr.clone()) }
+            (h < num::cast(5)) { RGB::new(x, zero!(T), chr.clone()) }
+            (h < num::cast(6)) { RGB::new(chr.clone(), zero!(T), x) }
+            _                  { RGB::new(zero!(T), zero!(T), zero!(T)) }
+        );
+
+        // match the lightness by adding the same amount to each component
+        let mn = (*self).l - chr / two!(T);
+
+        rgb.r = rgb.r + mn;
+        rgb.g = rgb.g + mn;
+        rgb.b = rgb.b + mn;
+
+        rgb.to_rgb::<U>()
+    }
+}
+
+#[deriving(Clone, Eq)]
+pub struct HSLA<T> { h: T, s: T, l: T, a: T }
+
+impl<T> HSLA<T> {
+    #[inline]
+    pub fn new(h: T, s: T, l: T, a: T) -> HSLA<T> {
+        HSLA { h: h, s: s, l: l, a: a }
+    }
+
+    #[inline]
+    pub fn from_hsl_a(hsl: HSL<T>, a: T) -> HSLA<T> {
+        unsafe { cast::transmute((hsl, a)) }
+    }
+
+    #[inline]
+    pub fn hsl<'a>(&'a self) -> &'a HSL<T> {
+        unsafe { cast::transmute(self) }
+    }
+
+    #[inline]
+    pub fn hsl_mut<'a>(&'a mut self) -> &'a mut HSL<T> {
+        unsafe { cast::transmute(self) }
+    }
+}
+
+pub trait ToHSLA {
+    pub fn to_hsla<U:Clone + FloatChannel>(&self) -> HSLA<U>;
+}
+
+impl<C: ToHSL, T:Clone + ToFloatChannel> ToHSLA for (C, T) {
+    #[inline]
+    pub fn to_hsla<U:Clone + FloatChannel>(&self) -> HSLA<U> {
+        match *self {
+            (ref hsl, ref a) =>  {
+                HSLA::from_hsl_a(
+                    hsl.to_hsl(),
+                    FloatChannel::from(a.clone())
+                )
+            }
+        }
+    }
+}
+
+impl<T:Clone + Float + ToChannel> ToRGBA for HSLA<T> {
+    #[inline]
+    pub fn to_rgba<U:Clone + Channel>(&self) -> RGBA<U> {
+        RGBA::from_rgb_a(
+            self.hsl().to_rgb(),
+            Channel::from((*self).a.clone())
+        )
+    }
+}
+
+impl<T:Clone + Float + ToChannel> ToHSL for RGB<T> {
+    pub fn to_hsl<U:Clone + FloatChannel>(&self) -> HSL<U> {
+        // Algorithm taken from the Wikipedia article on HSL and HSV:
+        // http://en.wikipedia.org/wiki/HSL_and_HSV#From_RGB
+
+        let mx = self.r.max(&self.g).max(&self.b);
+        let mn = self.r.min(&self.g).min(&self.b);
+        let chr = mx - mn;
+
+        let h = if chr == zero!(T) {
+            zero!(T)
+        } else {
+            let sextant = if mx == self.r {
+                (self.g - self.b) / chr
+            } else if mx == self.g {
+                (self.b - self.r) / chr + two!(T)
+            } else {
+                (self.r - self.g) / chr + num::cast(4)
+            };
+
+            let raw = sextant * num::cast(60);
+            if raw < zero!(T) { raw + num::cast(360) } else { raw }
+        };
+
+        let l = (mx + mn) / two!(T);
+
+        let s = if chr == zero!(T) {
+            zero!(T)
+        } else {
+            chr / (one!(T) - (two!(T) * l - one!(T)).abs())
+        };
+
+        HSL::new(h, s, l).to_hsl::<U>()
+    }
+}
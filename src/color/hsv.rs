@@ -128,3 +128,33 @@ impl<T:Clone + Float + ToChannel> ToRGBA for HSVA<T> {
         )
     }
 }
+
+impl<T:Clone + Float + ToChannel> ToHSV for RGB<T> {
+    pub fn to_hsv<U:Clone + FloatChannel>(&self) -> HSV<U> {
+        // Algorithm taken from the Wikipedia article on HSL and HSV:
+        // http://en.wikipedia.org/wiki/HSL_and_HSV#From_RGB
+
+        let mx = self.r.max(&self.g).max(&self.b);
+        let mn = self.r.min(&self.g).min(&self.b);
+        let chr = mx - mn;
+
+        let h = if chr == zero!(T) {
+            zero!(T)
+        } else {
+            let sextant = if mx == self.r {
+                (self.g - self.b) / chr
+            } else if mx == self.g {
+                (self.b - self.r) / chr + two!(T)
+            } else {
+                (self.r - self.g) / chr + num::cast(4)
+            };
+
+            let raw = sextant * num::cast(60);
+            if raw < zero!(T) { raw + num::cast(360) } else { raw }
+        };
+
+        let s = if mx == zero!(T) { zero!(T) } else { chr / mx };
+
+        HSV::new(h, s, mx).to_hsv::<U>()
+    }
+}